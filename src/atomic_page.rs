@@ -0,0 +1,128 @@
+use crate::*;
+use alloc::alloc::Global;
+use core::alloc::Allocator;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// The header stored by an [`AtomicPage`]: the user's header plus an atomic
+/// bump-allocation cursor over the data array.
+struct AtomicHeader<H> {
+    header: H,
+    cursor: AtomicU32,
+}
+
+/// A page that hands out data array slots to concurrent writers by
+/// compare-and-swapping an atomic cursor, rather than requiring a lock.
+///
+/// Call [`Self::reserve`] to CAS-advance the cursor and claim `n` consecutive
+/// slots starting at the returned index; write your [`MaybeUninit<T>`]s there
+/// via [`Self::data`]. The reserved range `[index, index + n)` is exclusively
+/// owned by the caller that reserved it, and no lock is held while it writes;
+/// other writers keep reserving further ranges concurrently.
+///
+/// The cursor's compare-and-swap only hands out ranges. It happens before the
+/// slots are written, so it does not make the written values visible to
+/// other threads, and this type has no sealing or publishing step: a reader
+/// must synchronise with the writer of a range by other means before reading
+/// its slots.
+///
+/// ## Example
+///
+/// ```
+/// use pages::AtomicPage;
+/// use core::mem::MaybeUninit;
+///
+/// let page = AtomicPage::<(), u32>::new((), 4);
+/// let base = page.reserve(2).expect("capacity for 2 slots");
+/// unsafe {
+///     page.data().add(base as usize).write(MaybeUninit::new(10));
+///     page.data().add(base as usize + 1).write(MaybeUninit::new(11));
+///     assert_eq!(page.data().add(base as usize).read().assume_init(), 10);
+/// }
+/// assert_eq!(page.reserve(3), None); // only 2 slots left
+/// ```
+///
+/// ## Notes
+///
+/// Like [`Page`], data is exposed as [`MaybeUninit`] pointers, so nothing
+/// here drops initialised elements for you.
+pub struct AtomicPage<H, T, A: Allocator = Global>(PageRef<AtomicHeader<H>, T, A>);
+
+impl<H, T> AtomicPage<H, T, Global> {
+    /// Creates a new [`AtomicPage`] on the heap with the provided header and
+    /// capacity for `items` items. The cursor starts at 0.
+    ///
+    /// ## Notes
+    ///
+    /// Will panic if items is 0 or the header plus padding is extremely large
+    /// (u32::MAX - 8 bytes)
+    pub fn new(header: H, items: u32) -> Self {
+        AtomicPage(PageRef::new(AtomicHeader { header, cursor: AtomicU32::new(0) }, items))
+    }
+}
+
+impl<H, T, A: Allocator> AtomicPage<H, T, A> {
+    /// Creates a new [`AtomicPage`] on `alloc` with the provided header and
+    /// capacity for `items` items. The cursor starts at 0.
+    ///
+    /// ## Notes
+    ///
+    /// Will panic if items is 0, the header plus padding is extremely large
+    /// (u32::MAX - 8 bytes), or `alloc` fails to allocate.
+    pub fn new_in(header: H, items: u32, alloc: A) -> Self {
+        AtomicPage(PageRef::new_in(AtomicHeader { header, cursor: AtomicU32::new(0) }, items, alloc))
+    }
+
+    /// The capacity of this page's data array.
+    #[inline(always)]
+    pub fn capacity(&self) -> u32 { unsafe { self.0.capacity() } }
+
+    /// Access to this page's user-chosen header by reference.
+    #[inline(always)]
+    pub fn header(&self) -> &H { &unsafe { self.0.header() }.header }
+
+    /// Reserves `n` consecutive data array slots, returning the base index of
+    /// the reserved range, or `None` if fewer than `n` slots remain.
+    ///
+    /// Reserving does not initialise the slots: the caller must write to
+    /// `[index, index + n)` via [`Self::data`] before any reader relies on
+    /// them being initialised.
+    pub fn reserve(&self, n: u32) -> Option<u32> {
+        let capacity = self.capacity();
+        let cursor = &unsafe { self.0.header() }.cursor;
+        let mut current = cursor.load(Ordering::Relaxed);
+        loop {
+            let next = current.checked_add(n)?;
+            if next > capacity { return None; }
+            match cursor.compare_exchange_weak(current, next, Ordering::AcqRel, Ordering::Relaxed) {
+                Ok(_) => return Some(current),
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Access to the start of the data array as a mut pointer.
+    ///
+    /// ## Safety
+    ///
+    /// You must only write to slots you've reserved with [`Self::reserve`],
+    /// and only read slots that some reservation has finished writing to, with
+    /// that write synchronised with the reading thread.
+    #[inline(always)]
+    pub unsafe fn data(&self) -> *mut MaybeUninit<T> { self.0.data() }
+}
+
+unsafe impl<H: Send, T: Send, A: Allocator + Send> Send for AtomicPage<H, T, A> {}
+unsafe impl<H: Sync, T: Sync, A: Allocator + Sync> Sync for AtomicPage<H, T, A> {}
+
+impl<H, T, A: Allocator> fmt::Debug for AtomicPage<H, T, A> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "AtomicPage[{}]", self.capacity())
+    }
+}
+
+impl<H, T, A: Allocator> Drop for AtomicPage<H, T, A> {
+    #[inline(always)] fn drop(&mut self) { unsafe { PageRef::drop(core::ptr::read(&self.0)) } }
+}
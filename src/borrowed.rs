@@ -0,0 +1,178 @@
+use crate::layout::*;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr::NonNull;
+
+/// A read-only, non-owning view of a page living in memory someone else
+/// manages (a memory-mapped file, a shared buffer, ...), tied to a lifetime
+/// `'a` instead of an allocation.
+///
+/// Unlike [`PageRef`](crate::PageRef) / [`Page`](crate::Page), dropping a
+/// [`PageView`] does nothing — it never allocated the memory, so it has
+/// nothing to free.
+///
+/// ## Example
+///
+/// ```
+/// use pages::{Page, PageLayout, PageView};
+/// use std::alloc::alloc;
+///
+/// let layout = PageLayout::<u32, u8>::with_capacity(4);
+/// unsafe {
+///     let ptr = alloc(layout.layout());
+///     let page = Page::<u32, u8>::from_uninit(ptr, 7, layout);
+///     let view = PageView::from_raw_parts(ptr, layout);
+///     assert_eq!(*view.header(), 7);
+///     assert_eq!(view.capacity(), 4);
+///     drop(page); // frees `ptr`; `view` must not outlive this
+/// }
+/// ```
+pub struct PageView<'a, H, T> {
+    inner: NonNull<u8>,
+    _phantom: PhantomData<(&'a PageHeader<H>, &'a T)>,
+}
+
+impl<'a, H, T> PageView<'a, H, T> {
+    /// Creates a [`PageView`] borrowing the page at `ptr` for the lifetime `'a`.
+    ///
+    /// ## Safety
+    ///
+    /// * `ptr` must point at an initialised `PageHeader<H>` followed by its data
+    ///   array, laid out according to `layout`. The data array itself may be
+    ///   uninitialised, as it is only exposed through [`MaybeUninit`] pointers.
+    /// * The pointee must remain valid, initialised and unmodified by anyone
+    ///   without synchronisation for the lifetime `'a`.
+    #[inline(always)]
+    pub unsafe fn from_raw_parts(ptr: *const u8, layout: PageLayout<H, T>) -> Self {
+        debug_assert_eq!((*ptr.cast::<PageHeader<H>>()).desc.items, layout.desc.items);
+        PageView { inner: NonNull::new_unchecked(ptr.cast_mut()), _phantom: PhantomData }
+    }
+
+    /// The capacity of this page's data array.
+    #[inline(always)]
+    pub fn capacity(&self) -> u32 { self.desc().items }
+
+    /// Access to this page's header by reference.
+    #[inline(always)]
+    pub fn header(&self) -> &'a H { unsafe { &(*self.page_header()).header } }
+
+    /// Access to the start of the data array as a const pointer.
+    #[inline(always)]
+    pub fn data(&self) -> *const MaybeUninit<T> {
+        let raw = self.inner.as_ptr();
+        let offset = self.desc().data;
+        unsafe { raw.add(offset as usize).cast() }
+    }
+
+    #[inline(always)]
+    fn desc(&self) -> PageDesc { unsafe { &*self.page_header() }.desc }
+
+    #[inline(always)]
+    fn page_header(&self) -> *mut PageHeader<H> { self.inner.as_ptr().cast::<PageHeader<H>>() }
+}
+
+impl<'a, H, T> Clone for PageView<'a, H, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, H, T> Copy for PageView<'a, H, T> {}
+
+unsafe impl<'a, H: Sync, T: Sync> Send for PageView<'a, H, T> {}
+unsafe impl<'a, H: Sync, T: Sync> Sync for PageView<'a, H, T> {}
+
+impl<'a, H, T> fmt::Debug for PageView<'a, H, T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "PageView[{}]", self.capacity())
+    }
+}
+
+/// A mutable, non-owning view of a page living in memory someone else manages
+/// (a memory-mapped file, a shared buffer, ...), tied to a lifetime `'a`
+/// instead of an allocation.
+///
+/// Unlike [`PageRef`](crate::PageRef) / [`Page`](crate::Page), dropping a
+/// [`PageMut`] does nothing — it never allocated the memory, so it has
+/// nothing to free.
+///
+/// ## Example
+///
+/// ```
+/// use pages::{Page, PageLayout, PageMut};
+/// use std::alloc::alloc;
+///
+/// let layout = PageLayout::<u32, u8>::with_capacity(4);
+/// unsafe {
+///     let ptr = alloc(layout.layout());
+///     let mut page = Page::<u32, u8>::from_uninit(ptr, 7, layout);
+///     let mut view = PageMut::from_raw_parts(ptr, layout);
+///     *view.header_mut() = 9;
+///     assert_eq!(*page.header(), 9);
+///     drop(page); // frees `ptr`; `view` must not outlive this
+/// }
+/// ```
+pub struct PageMut<'a, H, T> {
+    inner: NonNull<u8>,
+    _phantom: PhantomData<(&'a mut PageHeader<H>, &'a mut T)>,
+}
+
+impl<'a, H, T> PageMut<'a, H, T> {
+    /// Creates a [`PageMut`] borrowing the page at `ptr` for the lifetime `'a`.
+    ///
+    /// ## Safety
+    ///
+    /// * `ptr` must point at an initialised `PageHeader<H>` followed by its data
+    ///   array, laid out according to `layout`. The data array itself may be
+    ///   uninitialised, as it is only exposed through [`MaybeUninit`] pointers.
+    /// * The pointee must remain valid and accessed only through this
+    ///   [`PageMut`] for the lifetime `'a`.
+    #[inline(always)]
+    pub unsafe fn from_raw_parts(ptr: *mut u8, layout: PageLayout<H, T>) -> Self {
+        debug_assert_eq!((*ptr.cast::<PageHeader<H>>()).desc.items, layout.desc.items);
+        PageMut { inner: NonNull::new_unchecked(ptr), _phantom: PhantomData }
+    }
+
+    /// The capacity of this page's data array.
+    #[inline(always)]
+    pub fn capacity(&self) -> u32 { self.desc().items }
+
+    /// Access to this page's header by reference.
+    #[inline(always)]
+    pub fn header(&self) -> &H { unsafe { &(*self.page_header()).header } }
+
+    /// Access to this page's header by mut reference.
+    #[inline(always)]
+    pub fn header_mut(&mut self) -> &mut H { unsafe { &mut (*self.page_header()).header } }
+
+    /// Access to the start of the data array as a mut pointer.
+    #[inline(always)]
+    pub fn data(&self) -> *mut MaybeUninit<T> {
+        let raw = self.inner.as_ptr();
+        let offset = self.desc().data;
+        unsafe { raw.add(offset as usize).cast() }
+    }
+
+    /// Borrows this [`PageMut`] as a read-only [`PageView`].
+    #[inline(always)]
+    pub fn as_view(&self) -> PageView<'_, H, T> {
+        PageView { inner: self.inner, _phantom: PhantomData }
+    }
+
+    #[inline(always)]
+    fn desc(&self) -> PageDesc { unsafe { &*self.page_header() }.desc }
+
+    #[inline(always)]
+    fn page_header(&self) -> *mut PageHeader<H> { self.inner.as_ptr().cast::<PageHeader<H>>() }
+}
+
+unsafe impl<'a, H: Send, T: Send> Send for PageMut<'a, H, T> {}
+unsafe impl<'a, H: Sync, T: Sync> Sync for PageMut<'a, H, T> {}
+
+impl<'a, H, T> fmt::Debug for PageMut<'a, H, T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "PageMut[{}]", self.capacity())
+    }
+}
@@ -54,13 +54,21 @@ impl<H, T> PageLayout<H, T> {
     /// (u32::MAX - 8 bytes)
     #[inline(always)]
     pub fn with_capacity(items: u32) -> Self {
-        assert!(items > 0); // Use a box.
+        Self::try_with_capacity(items).expect("invalid item count")
+    }
+
+    /// Fallible version of [`Self::with_capacity`] that returns a [`PageLayoutError`]
+    /// instead of panicking when `items` is 0 or the layout would overflow.
+    #[inline(always)]
+    pub fn try_with_capacity(items: u32) -> Result<Self, PageLayoutError> {
+        if items == 0 { return Err(PageLayoutError::ZeroItems); }
         let header = Layout::new::<PageHeader<H>>();
-        let array = Layout::array::<T>(items as usize).unwrap();
-        let (layout, data) = header.extend(array).unwrap();
+        let array = Layout::array::<T>(items as usize).map_err(|_| PageLayoutError::Overflow)?;
+        let (layout, data) = header.extend(array).map_err(|_| PageLayoutError::Overflow)?;
         let layout = layout.pad_to_align();
-        let desc = PageDesc { items, data: data.try_into().unwrap() };
-        Self { desc, layout, _phantom: PhantomData }
+        let data = data.try_into().map_err(|_| PageLayoutError::Overflow)?;
+        let desc = PageDesc { items, data };
+        Ok(Self { desc, layout, _phantom: PhantomData })
     }
 
     /// Returns a [`Layout`] suitable for passing to [`alloc`] / [`dealloc`].
@@ -68,11 +76,34 @@ impl<H, T> PageLayout<H, T> {
     pub fn layout(self) -> Layout { self.layout }
 }
 
-impl<H, T> Clone for PageLayout<H, T> {
+/// An error produced when a requested [`PageLayout`] is not constructible, or
+/// when allocating memory for one fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageLayoutError {
+    /// Zero items were requested. Use a `Box` (or similar) instead.
+    ZeroItems,
+    /// The header, padding and data array together overflow the allocator's limits.
+    Overflow,
+    /// The allocator was unable to satisfy the allocation request.
+    AllocFailed,
+}
+
+impl fmt::Display for PageLayoutError {
     #[inline(always)]
-    fn clone(&self) -> Self {
-        PageLayout { desc: self.desc, layout: self.layout, _phantom: self._phantom }
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PageLayoutError::ZeroItems => write!(fmt, "zero items requested"),
+            PageLayoutError::Overflow => write!(fmt, "layout size overflows"),
+            PageLayoutError::AllocFailed => write!(fmt, "allocator failed to allocate"),
+        }
     }
 }
 
+impl core::error::Error for PageLayoutError {}
+
+impl<H, T> Clone for PageLayout<H, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+
 impl<H, T> Copy for PageLayout<H, T> {}
@@ -27,14 +27,32 @@
 //! maybe.put(42);
 //! assert_eq!(maybe.get(), Some(42));
 //! ```
+//!
+//! ## Allocators
+//!
+//! [`Page`] and [`PageRef`] take an optional [`core::alloc::Allocator`] type
+//! parameter (defaulting to [`alloc::alloc::Global`]), so pages can live inside
+//! an arena, a memory-mapped region or any other externally-owned allocation
+//! rather than always going through the global allocator. This currently
+//! requires the nightly `allocator_api` feature.
 #![no_std]
+#![feature(allocator_api)]
 extern crate alloc;
 
 mod layout;
-pub use layout::PageLayout;
+pub use layout::{PageLayout, PageLayoutError};
 
 mod page;
 pub use page::*;
 
 mod page_ref;
 pub use page_ref::*;
+
+mod page_vec;
+pub use page_vec::*;
+
+mod atomic_page;
+pub use atomic_page::*;
+
+mod borrowed;
+pub use borrowed::*;
@@ -1,6 +1,9 @@
 use crate::*;
+use alloc::alloc::Global;
+use core::alloc::Allocator;
 use core::fmt;
-use core::mem::{MaybeUninit, forget};
+use core::mem::{MaybeUninit, ManuallyDrop};
+use core::ptr::read;
 
 /// An owned, heap-backed, dynamically-sized data page comprising a user-chosen
 /// header and data array packed into a single allocation. It is an owned object and
@@ -37,10 +40,12 @@ use core::mem::{MaybeUninit, forget};
 /// Data is exposed as a [`MaybeUninit`] pointer for maximum flexibility.
 /// Unfortunately this means we're unable to automatically drop the data
 /// for you in our destructor. You could cause a memory leak if you don't.
-#[repr(transparent)]
-pub struct Page<H, T>(PageRef<H, T>);
+///
+/// `A` defaults to [`Global`], which is zero-sized, so `Page<H, T>` costs
+/// nothing beyond the pointer.
+pub struct Page<H, T, A: Allocator = Global>(PageRef<H, T, A>);
 
-impl<H, T> Page<H, T> {
+impl<H, T> Page<H, T, Global> {
     /// Creates a new [`Page`] on the heap with the provided header and capacity for
     /// `items` items.
     ///
@@ -50,6 +55,33 @@ impl<H, T> Page<H, T> {
     /// (u32::MAX - 8 bytes)
     pub fn new(header: H, items: u32) -> Self { Page(PageRef::new(header, items)) }
 
+    /// Creates a new [`Page`] from a pointer to uninitialised memory, a header and
+    /// a [`PageLayout`].
+    ///
+    /// ## Safety
+    ///
+    /// You must ensure:
+    ///
+    /// * The pointer was allocated according to the provided [`PageLayout`] using
+    ///   the global allocator.
+    ///   * Synchronise all reads and writes to
+    ///   * Suppress the destructor of all but one of them (e.g. by wrapping in [`ManuallyDrop`]).
+    #[inline(always)]
+    pub unsafe fn from_uninit(raw_ptr: *mut u8, header: H, layout: PageLayout<H, T>) -> Self {
+        Page(PageRef::from_uninit(raw_ptr, header, layout))
+    }
+}
+
+impl<H, T, A: Allocator> Page<H, T, A> {
+    /// Creates a new [`Page`] on `alloc` with the provided header and capacity
+    /// for `items` items.
+    ///
+    /// ## Notes
+    ///
+    /// Will panic if items is 0, the header plus padding is extremely large
+    /// (u32::MAX - 8 bytes), or `alloc` fails to allocate.
+    pub fn new_in(header: H, items: u32, alloc: A) -> Self { Page(PageRef::new_in(header, items, alloc)) }
+
     /// The capacity of this page's data array.
     #[inline(always)]
     pub fn capacity(&self) -> u32 { unsafe { self.0.capacity() } }
@@ -70,21 +102,75 @@ impl<H, T> Page<H, T> {
     #[inline(always)]
     pub fn layout(&self) -> PageLayout<H, T> { PageLayout::with_capacity(self.0.desc().items) }
 
-    /// Creates a new [`Page`] from a pointer to uninitialised memory, a header and
-    /// a [`PageLayout`].
+    /// Reallocates this page in place to have capacity for `new_items` items,
+    /// preserving the header and the first `min(old capacity, new_items)` data
+    /// elements.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `new_items` is 0, the new layout would overflow, or the
+    /// allocator fails to allocate. See [`Self::try_resize`] for a
+    /// non-panicking version.
+    #[inline(always)]
+    pub fn resize(&mut self, new_items: u32) { unsafe { self.0.resize(new_items) } }
+
+    /// Fallible version of [`Self::resize`] that returns a [`PageLayoutError`]
+    /// instead of panicking when `new_items` is 0, the new layout would
+    /// overflow, or the allocator fails to allocate.
+    #[inline(always)]
+    pub fn try_resize(&mut self, new_items: u32) -> Result<(), PageLayoutError> {
+        unsafe { self.0.try_resize(new_items) }
+    }
+
+    /// Copies `count` elements starting at `src_index` in `self` to
+    /// `dst_index` in `dst`. See [`PageRef::copy_to`] for the underlying
+    /// operation and its safety requirements.
+    ///
+    /// ## Safety
+    ///
+    /// See [`PageRef::copy_to`].
+    #[inline(always)]
+    pub unsafe fn copy_to(&self, dst: &Self, src_index: u32, dst_index: u32, count: u32) where T: Copy {
+        self.0.copy_to(&dst.0, src_index, dst_index, count)
+    }
+
+    /// Copies `count` elements within `self` from `src_index` to `dst_index`.
+    /// See [`PageRef::copy_within`] for the underlying operation and its
+    /// safety requirements.
+    ///
+    /// ## Safety
+    ///
+    /// See [`PageRef::copy_within`].
+    #[inline(always)]
+    pub unsafe fn copy_within(&self, src_index: u32, dst_index: u32, count: u32) where T: Copy {
+        self.0.copy_within(src_index, dst_index, count)
+    }
+
+    /// Relocates `count` elements starting at `src_index` in `self` to
+    /// `dst_index` in `dst`. See [`PageRef::move_to`] for the underlying
+    /// operation and its safety requirements.
+    ///
+    /// ## Safety
+    ///
+    /// See [`PageRef::move_to`].
+    #[inline(always)]
+    pub unsafe fn move_to(&self, dst: &Self, src_index: u32, dst_index: u32, count: u32) {
+        self.0.move_to(&dst.0, src_index, dst_index, count)
+    }
+
+    /// Creates a new [`Page`] from a pointer to uninitialised memory, a header,
+    /// a [`PageLayout`] and the allocator it was allocated with.
     ///
     /// ## Safety
     ///
     /// You must ensure:
     ///
-    /// * The pointer was allocated according to the provided [`PageLayout`].
-    ///   * Synchronise all reads and writes to 
+    /// * The pointer was allocated according to the provided [`PageLayout`] using `alloc`.
+    ///   * Synchronise all reads and writes to
     ///   * Suppress the destructor of all but one of them (e.g. by wrapping in [`ManuallyDrop`]).
-    /// * If the pointer did not originate from the global allocator, you must
-    ///   suppress the destructor (e.g. by wrapping in [`ManuallyDrop`]).
     #[inline(always)]
-    pub unsafe fn from_uninit(raw_ptr: *mut u8, header: H, layout: PageLayout<H, T>) -> Self {
-        Page(PageRef::from_uninit(raw_ptr, header, layout))
+    pub unsafe fn from_uninit_in(raw_ptr: *mut u8, header: H, layout: PageLayout<H, T>, alloc: A) -> Self {
+        Page(PageRef::from_uninit_in(raw_ptr, header, layout, alloc))
     }
 
     /// Creates an owned [`Page`] from a [`PageRef`].
@@ -101,28 +187,27 @@ impl<H, T> Page<H, T> {
     /// ## Safety
     ///
     /// You must only have one live [`Page`] per page.
-    pub unsafe fn from_ref(page_ref: PageRef<H, T>) -> Self { Page(page_ref) }
+    pub unsafe fn from_ref(page_ref: PageRef<H, T, A>) -> Self { Page(page_ref) }
 
     /// Converts this [`Page`] to a PageRef, a mutable pointer structure,
     /// effectively leaking it.
     #[inline(always)]
-    pub fn to_ref(self) -> PageRef<H, T> {
-        let r = self.0;
-        forget(self); // Disable our destructor.
-        r
+    pub fn to_ref(self) -> PageRef<H, T, A> {
+        let this = ManuallyDrop::new(self);
+        unsafe { read(&this.0) }
     }
 }
 
-unsafe impl<H: Send, T: Send> Send for Page<H, T> {}
-unsafe impl<H: Sync, T: Sync> Sync for Page<H, T> {}
+unsafe impl<H: Send, T: Send, A: Allocator + Send> Send for Page<H, T, A> {}
+unsafe impl<H: Sync, T: Sync, A: Allocator + Sync> Sync for Page<H, T, A> {}
 
-impl<H, T> fmt::Debug for Page<H, T> {
+impl<H, T, A: Allocator> fmt::Debug for Page<H, T, A> {
     #[inline(always)]
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         write!(fmt, "Page[{}]", self.capacity())
     }
 }
 
-impl<H, T> Drop for Page<H, T> {
-    #[inline(always)] fn drop(&mut self) { unsafe { PageRef::drop(self.0) } }
+impl<H, T, A: Allocator> Drop for Page<H, T, A> {
+    #[inline(always)] fn drop(&mut self) { unsafe { PageRef::drop(read(&self.0)) } }
 }
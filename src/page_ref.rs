@@ -1,13 +1,15 @@
 use crate::layout::*;
-use alloc::alloc::{alloc, dealloc};
+use alloc::alloc::Global;
+use core::alloc::Allocator;
 use core::fmt;
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
-use core::ptr::{NonNull, drop_in_place};
+use core::ptr::{NonNull, drop_in_place, copy, copy_nonoverlapping};
 
 /// A mutable pointer to a dynamically-sized heap-backed data page
 /// comprising a user-chosen header and data array packed into a
-/// single allocation. The internal representation is a [`NonNull`].
+/// single allocation. The internal representation is a [`NonNull`]
+/// plus the allocator handle `A` the page was allocated with.
 ///
 /// ## Example
 ///
@@ -40,27 +42,33 @@ use core::ptr::{NonNull, drop_in_place};
 /// Data is exposed as a [`MaybeUninit`] pointer for maximum flexibility.
 /// Unfortunately this means we're unable to automatically drop the data
 /// for you in our destructor. You could cause a memory leak if you don't.
-#[repr(transparent)]
-pub struct PageRef<H, T> {
+///
+/// `A` defaults to [`Global`], which is zero-sized, so `PageRef<H, T>` costs
+/// nothing beyond the pointer. A non-zero-sized allocator handle (e.g. a
+/// handle borrowing an arena) makes the struct correspondingly larger.
+pub struct PageRef<H, T, A: Allocator = Global> {
     inner: NonNull<u8>,
-    _phantom: PhantomData<(H,T)>,
+    alloc: A,
+    _phantom: PhantomData<(H, T)>,
 }
 
-impl<H, T> Eq for PageRef<H, T> {}
+impl<H, T, A: Allocator> Eq for PageRef<H, T, A> {}
 
-impl<H, T> PartialEq  for PageRef<H, T> {
+impl<H, T, A: Allocator> PartialEq for PageRef<H, T, A> {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool { self.inner == other.inner }
 }
 
-impl<H, T> Clone for PageRef<H, T> {
+impl<H, T, A: Allocator + Clone> Clone for PageRef<H, T, A> {
     #[inline(always)]
-    fn clone(&self) -> Self { PageRef { inner: self.inner, _phantom: self._phantom } }
+    fn clone(&self) -> Self {
+        PageRef { inner: self.inner, alloc: self.alloc.clone(), _phantom: self._phantom }
+    }
 }
 
-impl<H, T> Copy for PageRef<H, T> {}
+impl<H, T, A: Allocator + Copy> Copy for PageRef<H, T, A> {}
 
-impl<H, T> PageRef<H, T> {
+impl<H, T> PageRef<H, T, Global> {
     /// Creates a new [`PageRef`] on the heap with the provided header and capacity for
     /// `items` items.
     ///
@@ -69,13 +77,79 @@ impl<H, T> PageRef<H, T> {
     /// Will panic if items is 0 or the header plus padding is extremely large
     /// (u32::MAX - 8 bytes)
     #[inline(always)]
-    pub fn new(header: H, items: u32) -> Self {
+    pub fn new(header: H, items: u32) -> Self { Self::new_in(header, items, Global) }
+
+    /// Creates a new [`PageRef`] from a pointer to uninitialised memory, a header and
+    /// a [`PageLayout`].
+    ///
+    /// ## Safety
+    ///
+    /// The pointer must have been allocated according to the provided [`PageLayout`]
+    /// using the global allocator.
+    #[inline(always)]
+    pub unsafe fn from_uninit(raw_ptr: *mut u8, header: H, layout: PageLayout<H, T>) -> Self {
+        Self::from_uninit_in(raw_ptr, header, layout, Global)
+    }
+}
+
+impl<H, T, A: Allocator> PageRef<H, T, A> {
+    /// Creates a new [`PageRef`] on `alloc` with the provided header and capacity
+    /// for `items` items.
+    ///
+    /// ## Example
+    ///
+    /// Proves the allocator parameterization actually works end-to-end: the
+    /// page's memory comes from a custom bump arena, not [`Global`].
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    /// use pages::PageRef;
+    /// use core::alloc::{AllocError, Allocator, Layout};
+    /// use core::cell::Cell;
+    /// use core::ptr::NonNull;
+    ///
+    /// /// Hands out consecutive slices of a fixed buffer; never actually frees.
+    /// struct Bump { buf: Box<[u8]>, used: Cell<usize> }
+    ///
+    /// unsafe impl Allocator for Bump {
+    ///     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+    ///         let start = self.buf.as_ptr() as usize;
+    ///         let aligned = (start + self.used.get()).next_multiple_of(layout.align());
+    ///         let offset = aligned - start;
+    ///         let end = offset + layout.size();
+    ///         if end > self.buf.len() { return Err(AllocError); }
+    ///         self.used.set(end);
+    ///         let ptr = unsafe { NonNull::new_unchecked(self.buf.as_ptr().add(offset).cast_mut()) };
+    ///         Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    ///     }
+    ///     unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+    /// }
+    ///
+    /// let bump = Bump { buf: vec![0u8; 128].into_boxed_slice(), used: Cell::new(0) };
+    /// let base = bump.buf.as_ptr() as usize;
+    /// let bound = base + bump.buf.len();
+    ///
+    /// let page = PageRef::<u32, u8, &Bump>::new_in(7, 4, &bump);
+    /// unsafe {
+    ///     assert_eq!(*page.header(), 7);
+    ///     let addr = page.data() as usize;
+    ///     assert!(addr >= base && addr < bound); // lives inside the arena, not the heap
+    ///     PageRef::drop(page);
+    /// }
+    /// ```
+    ///
+    /// ## Notes
+    ///
+    /// Will panic if items is 0, the header plus padding is extremely large
+    /// (u32::MAX - 8 bytes), or `alloc` fails to allocate.
+    #[inline(always)]
+    pub fn new_in(header: H, items: u32, alloc: A) -> Self {
         // In order to safely allocate and use the memory, we create a `PageLayout`,
         // which encapsulates all the knowledge we need. The safety of everything
         // hinges on the correctness of the `PageLayout`.
         let layout = PageLayout::<H, T>::with_capacity(items);
-        let ptr = unsafe { alloc(layout.layout()) }; // Allocate.
-        unsafe { Self::from_uninit(ptr, header, layout) }   // Initialise.
+        let ptr = alloc.allocate(layout.layout()).expect("allocation failed");
+        unsafe { Self::from_uninit_in(ptr.as_ptr().cast(), header, layout, alloc) } // Initialise.
     }
 
     /// The capacity of this page's data array.
@@ -84,7 +158,7 @@ impl<H, T> PageRef<H, T> {
     ///
     /// You must synchronise all reads and writes.
     #[inline(always)]
-    pub unsafe fn capacity(self) -> u32 { self.desc().items }
+    pub unsafe fn capacity(&self) -> u32 { self.desc().items }
 
     /// Access to this page's header by reference.
     ///
@@ -108,7 +182,7 @@ impl<H, T> PageRef<H, T> {
     ///
     /// You must synchronise all reads and writes.
     #[inline(always)]
-    pub unsafe fn data(self) -> *mut MaybeUninit<T> {
+    pub unsafe fn data(&self) -> *mut MaybeUninit<T> {
         let raw = self.inner.as_ptr();
         let offset = (*raw.cast::<PageHeader<H>>()).desc.data;
         raw.add(offset as usize).cast()
@@ -120,7 +194,203 @@ impl<H, T> PageRef<H, T> {
     ///
     /// You must synchronise all reads and writes.
     #[inline(always)]
-    pub unsafe fn layout(self) -> PageLayout<H, T> { PageLayout::with_capacity(self.desc().items) }
+    pub unsafe fn layout(&self) -> PageLayout<H, T> { PageLayout::with_capacity(self.desc().items) }
+
+    /// Reallocates the backing page in place to have capacity for `new_items`
+    /// items, like a WASM linear memory grow.
+    ///
+    /// Builds a fresh [`PageLayout`] for `new_items`, allocates it via this
+    /// page's allocator, moves the header across and copies the first
+    /// `min(old capacity, new_items)` data elements to the data offset of the
+    /// new layout, then frees the old allocation and repoints `self` at the
+    /// new one. Elements beyond `new_items` are not dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use pages::PageRef;
+    /// use core::mem::MaybeUninit;
+    ///
+    /// // A `u8` header next to `u32` data leaves padding before the data
+    /// // array; growing then shrinking must carry the elements across to the
+    /// // data offset of each capacity's own layout.
+    /// let mut page = PageRef::<u8, u32>::new(0, 2);
+    /// unsafe {
+    ///     page.data().write(MaybeUninit::new(10));
+    ///     page.data().add(1).write(MaybeUninit::new(20));
+    ///
+    ///     page.resize(4); // grow
+    ///     assert_eq!(page.capacity(), 4);
+    ///     assert_eq!(page.data().read().assume_init(), 10);
+    ///     assert_eq!(page.data().add(1).read().assume_init(), 20);
+    ///     page.data().add(2).write(MaybeUninit::new(30));
+    ///
+    ///     page.resize(1); // shrink, discarding the out-of-range elements without dropping them
+    ///     assert_eq!(page.capacity(), 1);
+    ///     assert_eq!(page.data().read().assume_init(), 10);
+    ///
+    ///     PageRef::drop(page);
+    /// }
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `new_items` is 0, the new layout would overflow, or the
+    /// allocator fails to allocate. See [`Self::try_resize`] for a
+    /// non-panicking version.
+    ///
+    /// ## Safety
+    ///
+    /// You must synchronise all reads and writes, and every other [`PageRef`]
+    /// pointing at this page is invalidated by the reallocation.
+    pub unsafe fn resize(&mut self, new_items: u32) {
+        self.try_resize(new_items).expect("invalid item count or allocation failure")
+    }
+
+    /// Fallible version of [`Self::resize`] that returns a [`PageLayoutError`]
+    /// instead of panicking when `new_items` is 0, the new layout would
+    /// overflow, or the allocator fails to allocate.
+    ///
+    /// ## Safety
+    ///
+    /// See [`Self::resize`].
+    pub unsafe fn try_resize(&mut self, new_items: u32) -> Result<(), PageLayoutError> {
+        let old_desc = self.desc();
+        let old_layout = PageLayout::<H, T>::with_capacity(old_desc.items);
+        let new_layout = PageLayout::<H, T>::try_with_capacity(new_items)?;
+        let new_ptr = self.alloc.allocate(new_layout.layout())
+            .map_err(|_| PageLayoutError::AllocFailed)?
+            .as_ptr().cast::<u8>();
+        let old_raw = self.inner.as_ptr();
+        // Move the header across, then fix up its `desc` to describe the new layout.
+        old_raw.cast::<PageHeader<H>>().copy_to_nonoverlapping(new_ptr.cast::<PageHeader<H>>(), 1);
+        (*new_ptr.cast::<PageHeader<H>>()).desc = new_layout.desc;
+        // Each side of the copy uses its own layout's `desc.data` rather than
+        // assuming the two offsets are equal.
+        let copy_items = old_desc.items.min(new_items) as usize;
+        let old_data = old_raw.add(old_desc.data as usize).cast::<T>();
+        let new_data = new_ptr.add(new_layout.desc.data as usize).cast::<T>();
+        copy_nonoverlapping(old_data, new_data, copy_items);
+        self.alloc.deallocate(self.inner, old_layout.layout());
+        self.inner = NonNull::new_unchecked(new_ptr);
+        Ok(())
+    }
+
+    /// Copies `count` elements starting at `src_index` in `self` to
+    /// `dst_index` in `dst`.
+    ///
+    /// For `Copy`/trivially-relocatable `T` this is a single
+    /// [`ptr::copy_nonoverlapping`](core::ptr::copy_nonoverlapping) between
+    /// the two pages' `data()` pointers.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use pages::PageRef;
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let src = PageRef::<(), u32>::new((), 4);
+    /// let dst = PageRef::<(), u32>::new((), 4);
+    /// unsafe {
+    ///     for i in 0..4 { src.data().add(i).write(MaybeUninit::new(i as u32)); }
+    ///     src.copy_to(&dst, 0, 0, 4);
+    ///     assert_eq!(dst.data().add(3).read().assume_init(), 3);
+    ///     PageRef::drop(src);
+    ///     PageRef::drop(dst);
+    /// }
+    /// ```
+    ///
+    /// ## Safety
+    ///
+    /// * `src_index + count` must not exceed `self`'s capacity, and
+    ///   `dst_index + count` must not exceed `dst`'s capacity.
+    /// * `self` and `dst`'s element ranges must not overlap (use
+    ///   [`Self::copy_within`] to move elements around inside one page).
+    /// * You must synchronise all reads and writes.
+    pub unsafe fn copy_to(&self, dst: &Self, src_index: u32, dst_index: u32, count: u32) where T: Copy {
+        debug_assert!(src_index.checked_add(count).is_some_and(|end| end <= self.capacity()));
+        debug_assert!(dst_index.checked_add(count).is_some_and(|end| end <= dst.capacity()));
+        let src = self.data().add(src_index as usize).cast::<T>();
+        let dst = dst.data().add(dst_index as usize).cast::<T>();
+        copy_nonoverlapping(src, dst, count as usize);
+    }
+
+    /// Copies `count` elements within `self` from `src_index` to `dst_index`.
+    /// Unlike [`Self::copy_to`], the source and destination ranges may
+    /// overlap, as this uses [`ptr::copy`](core::ptr::copy) rather than the
+    /// nonoverlapping fast path.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use pages::PageRef;
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let page = PageRef::<(), u32>::new((), 4);
+    /// unsafe {
+    ///     for i in 0..4 { page.data().add(i).write(MaybeUninit::new(i as u32)); }
+    ///     page.copy_within(0, 1, 3); // shift [0, 1, 2] right by one, overlapping
+    ///     assert_eq!(page.data().add(1).read().assume_init(), 0);
+    ///     assert_eq!(page.data().add(3).read().assume_init(), 2);
+    ///     PageRef::drop(page);
+    /// }
+    /// ```
+    ///
+    /// ## Safety
+    ///
+    /// * Both `src_index + count` and `dst_index + count` must not exceed
+    ///   `self`'s capacity.
+    /// * You must synchronise all reads and writes.
+    pub unsafe fn copy_within(&self, src_index: u32, dst_index: u32, count: u32) where T: Copy {
+        debug_assert!(src_index.checked_add(count).is_some_and(|end| end <= self.capacity()));
+        debug_assert!(dst_index.checked_add(count).is_some_and(|end| end <= self.capacity()));
+        let base = self.data().cast::<T>();
+        copy(base.add(src_index as usize), base.add(dst_index as usize), count as usize);
+    }
+
+    /// Relocates `count` elements starting at `src_index` in `self` to
+    /// `dst_index` in `dst`, for any `T`, not just `Copy`/trivially-relocatable
+    /// types.
+    ///
+    /// Each slot is `read` from the source and `write`n to the destination in
+    /// turn, so every element is moved exactly once. After this call the
+    /// caller owns the elements only at the destination: the source slots are
+    /// left logically uninitialised, and must not be read (or dropped) again.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use pages::PageRef;
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let src = PageRef::<(), u32>::new((), 2);
+    /// let dst = PageRef::<(), u32>::new((), 2);
+    /// unsafe {
+    ///     src.data().write(MaybeUninit::new(42));
+    ///     src.move_to(&dst, 0, 0, 1);
+    ///     assert_eq!(dst.data().read().assume_init(), 42);
+    ///     PageRef::drop(src);
+    ///     PageRef::drop(dst);
+    /// }
+    /// ```
+    ///
+    /// ## Safety
+    ///
+    /// * `src_index + count` must not exceed `self`'s capacity, and
+    ///   `dst_index + count` must not exceed `dst`'s capacity.
+    /// * Every source slot in `[src_index, src_index + count)` must be
+    ///   initialised.
+    /// * `self` and `dst`'s element ranges must not overlap.
+    /// * You must synchronise all reads and writes.
+    pub unsafe fn move_to(&self, dst: &Self, src_index: u32, dst_index: u32, count: u32) {
+        debug_assert!(src_index.checked_add(count).is_some_and(|end| end <= self.capacity()));
+        debug_assert!(dst_index.checked_add(count).is_some_and(|end| end <= dst.capacity()));
+        let src = self.data().add(src_index as usize);
+        let dst = dst.data().add(dst_index as usize);
+        for i in 0..count as usize {
+            dst.add(i).write(src.add(i).read());
+        }
+    }
 
     /// Drops the page pointed to by the provided [`PageRef`]
     ///
@@ -131,17 +401,18 @@ impl<H, T> PageRef<H, T> {
         let raw = page.inner.as_ptr();
         drop_in_place(raw.cast::<PageHeader<H>>());
         let layout = PageLayout::<H, T>::with_capacity(page.desc().items);
-        dealloc(raw, layout.layout());
+        page.alloc.deallocate(page.inner, layout.layout());
     }
 
-    /// Creates a new [`PageRef`] from a pointer to uninitialised memory, a header and
-    /// a [`PageLayout`].
+    /// Creates a new [`PageRef`] from a pointer to uninitialised memory, a
+    /// header, a [`PageLayout`] and the allocator it was allocated with.
     ///
     /// ## Safety
     ///
-    /// The pointer must have been allocated according to the provided [`PageLayout`].
+    /// The pointer must have been allocated according to the provided
+    /// [`PageLayout`] using `alloc`.
     #[inline(always)]
-    pub unsafe fn from_uninit(raw_ptr: *mut u8, header: H, layout: PageLayout<H, T>) -> Self {
+    pub unsafe fn from_uninit_in(raw_ptr: *mut u8, header: H, layout: PageLayout<H, T>, alloc: A) -> Self {
         // Prepare pointers to what we need to initialise. All safe if
         // you trust the layout is correct, which is presumed throughout.
         let header_ptr = raw_ptr.cast::<PageHeader<H>>();
@@ -149,19 +420,19 @@ impl<H, T> PageRef<H, T> {
         // Now we need to do that initialisation.
         header_ptr.write(header);
         let inner = NonNull::new_unchecked(header_ptr.cast());
-        PageRef { inner, _phantom: PhantomData }
+        PageRef { inner, alloc, _phantom: PhantomData }
     }
 
     #[inline(always)]
     /// Returns the descriptor for this page.
-    pub(crate) fn desc(self) -> PageDesc { unsafe { &*self.page_header() }.desc }
+    pub(crate) fn desc(&self) -> PageDesc { unsafe { &*self.page_header() }.desc }
 
     #[inline(always)]
     /// Returns the page header for this page.
-    pub(crate) fn page_header(self) -> *mut PageHeader<H> { self.inner.as_ptr().cast::<PageHeader<H>>() }
+    pub(crate) fn page_header(&self) -> *mut PageHeader<H> { self.inner.as_ptr().cast::<PageHeader<H>>() }
 }
 
-impl<H, T> fmt::Debug for PageRef<H, T> {
+impl<H, T, A: Allocator> fmt::Debug for PageRef<H, T, A> {
     #[inline(always)]
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result { write!(fmt, "PageRef {{}}") }
 }
@@ -0,0 +1,120 @@
+use crate::*;
+use alloc::alloc::Global;
+use core::alloc::Allocator;
+use core::fmt;
+use core::ptr::{drop_in_place, read, slice_from_raw_parts_mut};
+
+/// A length-tracked, auto-dropping vector built on [`PageRef`].
+///
+/// Unlike [`Page`], which exposes its data array as raw [`MaybeUninit`](core::mem::MaybeUninit)
+/// and can't drop it for you, `PageVec<T>` maintains an initialised prefix
+/// `[0, len)` of its fixed-capacity backing page and drops exactly that prefix
+/// when it goes out of scope. The header is the `u32` length.
+///
+/// ## Example
+///
+/// ```
+/// use pages::PageVec;
+/// let mut v = PageVec::<u32>::new(4);
+/// assert!(v.push(1).is_ok());
+/// assert!(v.push(2).is_ok());
+/// assert_eq!(v.as_slice(), &[1, 2]);
+/// assert_eq!(v.pop(), Some(2));
+/// ```
+///
+/// ## Notes
+///
+/// Capacity is fixed at construction, like the [`Page`] it's built on. `push`
+/// returns the value back in `Err` if the page is already full rather than
+/// growing it.
+pub struct PageVec<T, A: Allocator = Global>(PageRef<u32, T, A>);
+
+impl<T> PageVec<T, Global> {
+    /// Creates a new, empty [`PageVec`] on the heap with capacity for `items` items.
+    ///
+    /// ## Notes
+    ///
+    /// Will panic if items is 0 or the header plus padding is extremely large
+    /// (u32::MAX - 8 bytes)
+    pub fn new(items: u32) -> Self { PageVec(PageRef::new(0, items)) }
+}
+
+impl<T, A: Allocator> PageVec<T, A> {
+    /// Creates a new, empty [`PageVec`] on `alloc` with capacity for `items` items.
+    ///
+    /// ## Notes
+    ///
+    /// Will panic if items is 0, the header plus padding is extremely large
+    /// (u32::MAX - 8 bytes), or `alloc` fails to allocate.
+    pub fn new_in(items: u32, alloc: A) -> Self { PageVec(PageRef::new_in(0, items, alloc)) }
+
+    /// The number of initialised elements in this [`PageVec`].
+    #[inline(always)]
+    pub fn len(&self) -> u32 { *unsafe { self.0.header() } }
+
+    /// Whether this [`PageVec`] contains no elements.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// The capacity of this [`PageVec`]'s backing page.
+    #[inline(always)]
+    pub fn capacity(&self) -> u32 { unsafe { self.0.capacity() } }
+
+    /// Appends `value` to the end of this [`PageVec`].
+    ///
+    /// Returns `value` back in `Err` if the page is already full.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let len = self.len();
+        if len == self.capacity() { return Err(value); }
+        unsafe {
+            self.0.data().add(len as usize).write(core::mem::MaybeUninit::new(value));
+            *self.0.header_mut() = len + 1;
+        }
+        Ok(())
+    }
+
+    /// Removes and returns the last element of this [`PageVec`], or `None` if
+    /// it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len();
+        if len == 0 { return None; }
+        let new_len = len - 1;
+        unsafe {
+            *self.0.header_mut() = new_len;
+            Some(self.0.data().add(new_len as usize).read().assume_init())
+        }
+    }
+
+    /// Returns the initialised prefix of this [`PageVec`] as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.0.data().cast::<T>(), self.len() as usize) }
+    }
+
+    /// Returns the initialised prefix of this [`PageVec`] as a mutable slice.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len() as usize;
+        unsafe { core::slice::from_raw_parts_mut(self.0.data().cast::<T>(), len) }
+    }
+}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for PageVec<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for PageVec<T, A> {}
+
+impl<T, A: Allocator> fmt::Debug for PageVec<T, A> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "PageVec[{}/{}]", self.len(), self.capacity())
+    }
+}
+
+impl<T, A: Allocator> Drop for PageVec<T, A> {
+    fn drop(&mut self) {
+        let len = self.len() as usize;
+        unsafe {
+            drop_in_place(slice_from_raw_parts_mut(self.0.data().cast::<T>(), len));
+            PageRef::drop(read(&self.0));
+        }
+    }
+}